@@ -0,0 +1,12 @@
+use notify_rust::Notification;
+
+/// Fires a desktop notification for a phase transition.
+///
+/// Failures (no notification daemon running, headless session, ...) are
+/// logged and swallowed: a missed notification shouldn't take down the
+/// timer, since the on-screen countdown already shows the same thing.
+pub fn alert(title: &str, message: &str) {
+    if let Err(err) = Notification::new().summary(title).body(message).show() {
+        eprintln!("porsmo: failed to show desktop notification: {err}");
+    }
+}
@@ -0,0 +1,41 @@
+use rodio::{Decoder, OutputStream, Sink};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+/// Chime bundled with the binary, used when no `sound_file` is configured.
+const DEFAULT_CHIME: &[u8] = include_bytes!("../assets/chime.wav");
+
+/// Plays `path` (or the bundled default chime) to completion on a detached
+/// thread so the caller's TUI loop keeps ticking while the sound plays.
+///
+/// Playback errors (missing file, no output device, bad codec) are logged
+/// and otherwise ignored; a failed alert sound should never crash the timer.
+pub fn play_alert_sound(path: Option<&Path>) {
+    let path = path.map(PathBuf::from);
+
+    thread::spawn(move || {
+        if let Err(err) = play_blocking(path.as_deref()) {
+            eprintln!("porsmo: failed to play alert sound: {err}");
+        }
+    });
+}
+
+fn play_blocking(path: Option<&Path>) -> anyhow::Result<()> {
+    let (_stream, stream_handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&stream_handle)?;
+
+    match path {
+        Some(path) => {
+            let file = BufReader::new(File::open(path)?);
+            sink.append(Decoder::new(file)?);
+        }
+        None => {
+            sink.append(Decoder::new(BufReader::new(DEFAULT_CHIME))?);
+        }
+    }
+
+    sink.sleep_until_end();
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::Print;
+use crossterm::terminal;
+use std::io::Write;
+
+/// Height, in rows, of every glyph in the block font.
+const GLYPH_HEIGHT: usize = 5;
+
+/// 5-row block glyphs for the digits and the `:` separator, used to draw
+/// the countdown large enough to read from across a room.
+fn glyph_rows(ch: char) -> [&'static str; GLYPH_HEIGHT] {
+    match ch {
+        '0' => ["███", "█ █", "█ █", "█ █", "███"],
+        '1' => ["  █", "  █", "  █", "  █", "  █"],
+        '2' => ["███", "  █", "███", "█  ", "███"],
+        '3' => ["███", "  █", "███", "  █", "███"],
+        '4' => ["█ █", "█ █", "███", "  █", "  █"],
+        '5' => ["███", "█  ", "███", "  █", "███"],
+        '6' => ["███", "█  ", "███", "█ █", "███"],
+        '7' => ["███", "  █", "  █", "  █", "  █"],
+        '8' => ["███", "█ █", "███", "█ █", "███"],
+        '9' => ["███", "█ █", "███", "  █", "███"],
+        ':' => [" ", "█", " ", "█", " "],
+        '+' => ["   ", " █ ", "███", " █ ", "   "],
+        _ => ["   ", "   ", "   ", "   ", "   "],
+    }
+}
+
+/// Lays `text` (e.g. `"24:59"`) out as a grid of block-glyph rows, one
+/// row of strings per glyph row, with a column of padding between glyphs.
+fn render_rows(text: &str) -> Vec<String> {
+    let mut rows = vec![String::new(); GLYPH_HEIGHT];
+
+    for ch in text.chars() {
+        let glyph = glyph_rows(ch);
+        for (row, glyph_row) in rows.iter_mut().zip(glyph.iter()) {
+            row.push_str(glyph_row);
+            row.push(' ');
+        }
+    }
+
+    rows
+}
+
+/// Draws `text` as large block digits, centered in the terminal.
+///
+/// Falls back to printing `text` as a single compact line at the top-left
+/// when the terminal is too small to fit the block rendering, so the
+/// timer stays readable rather than clipping off-screen.
+pub fn show_big_text(out: &mut impl Write, text: &str, top: u16) -> Result<()> {
+    let (term_width, term_height) = terminal::size().context("failed to read terminal size")?;
+    let rows = render_rows(text);
+
+    let block_width = rows.first().map(|row| row.chars().count()).unwrap_or(0) as u16;
+    let block_height = rows.len() as u16;
+
+    if block_width > term_width || top + block_height > term_height {
+        queue!(out, MoveTo(0, top), Print(text))?;
+        return Ok(());
+    }
+
+    let start_col = (term_width.saturating_sub(block_width)) / 2;
+    for (i, row) in rows.iter().enumerate() {
+        queue!(out, MoveTo(start_col, top + i as u16), Print(row.clone()))?;
+    }
+
+    Ok(())
+}
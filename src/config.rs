@@ -0,0 +1,155 @@
+use crate::prelude::*;
+use directories::ProjectDirs;
+use porsmo::pomodoro::PomoConfig;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// On-disk representation of `settings.toml`.
+///
+/// Every field is optional so a user only needs to set the values they
+/// care about overriding; anything left out falls back to `PomoConfig`'s
+/// own defaults.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ConfigFile {
+    pub work_time: Option<u64>,
+    pub short_break: Option<u64>,
+    pub long_break: Option<u64>,
+    pub sessions_before_long_break: Option<u32>,
+    pub sound_file: Option<PathBuf>,
+    pub sound_enabled: Option<bool>,
+    pub compact_time: Option<bool>,
+    pub big_text: Option<bool>,
+}
+
+/// CLI-supplied overrides, applied on top of the config file.
+#[derive(Debug, Default)]
+pub struct ConfigOverrides {
+    pub work_time: Option<Duration>,
+    pub short_break: Option<Duration>,
+    pub long_break: Option<Duration>,
+    pub sessions_before_long_break: Option<u32>,
+    pub sound_file: Option<PathBuf>,
+    pub no_sound: bool,
+    pub compact_time: Option<bool>,
+    pub big_text: Option<bool>,
+}
+
+fn project_dirs() -> Result<ProjectDirs> {
+    ProjectDirs::from("", "", "porsmo").context("could not determine a config directory")
+}
+
+pub fn config_dir() -> Result<PathBuf> {
+    Ok(project_dirs()?.config_dir().to_path_buf())
+}
+
+pub fn config_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("settings.toml"))
+}
+
+/// Reads `settings.toml` from the config directory, if it exists.
+///
+/// A missing file is not an error; it just means the user has never saved
+/// one, so we fall back to an empty `ConfigFile`.
+pub fn load_config_file() -> Result<ConfigFile> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(ConfigFile::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read config file at {}", path.display()))?;
+    toml::from_str(&contents)
+        .with_context(|| format!("failed to parse config file at {}", path.display()))
+}
+
+/// Builds the effective `PomoConfig` by layering the config file over the
+/// built-in defaults, then the CLI overrides over that.
+pub fn load_config(overrides: ConfigOverrides) -> Result<PomoConfig> {
+    let file = load_config_file()?;
+    let mut config = PomoConfig::default();
+
+    if let Some(secs) = file.work_time {
+        config.work_time = Duration::from_secs(secs);
+    }
+    if let Some(secs) = file.short_break {
+        config.short_break = Duration::from_secs(secs);
+    }
+    if let Some(secs) = file.long_break {
+        config.long_break = Duration::from_secs(secs);
+    }
+    if let Some(n) = file.sessions_before_long_break {
+        config.sessions_before_long_break = n;
+    }
+    if let Some(sound_file) = file.sound_file {
+        config.sound_file = Some(sound_file);
+    }
+    if let Some(sound_enabled) = file.sound_enabled {
+        config.sound_enabled = sound_enabled;
+    }
+    if let Some(compact_time) = file.compact_time {
+        config.compact_time = compact_time;
+    }
+    if let Some(big_text) = file.big_text {
+        config.big_text = big_text;
+    }
+
+    if let Some(work_time) = overrides.work_time {
+        config.work_time = work_time;
+    }
+    if let Some(short_break) = overrides.short_break {
+        config.short_break = short_break;
+    }
+    if let Some(long_break) = overrides.long_break {
+        config.long_break = long_break;
+    }
+    if let Some(n) = overrides.sessions_before_long_break {
+        config.sessions_before_long_break = n;
+    }
+    if let Some(sound_file) = overrides.sound_file {
+        config.sound_file = Some(sound_file);
+    }
+    if overrides.no_sound {
+        config.sound_enabled = false;
+    }
+    if let Some(compact_time) = overrides.compact_time {
+        config.compact_time = compact_time;
+    }
+    if let Some(big_text) = overrides.big_text {
+        config.big_text = big_text;
+    }
+
+    Ok(config)
+}
+
+/// Serializes the effective config back out to `settings.toml`, creating
+/// the config directory if it doesn't exist yet. This is what backs the
+/// `--save` flag: the user can tune values on the command line once and
+/// have them become the new defaults.
+pub fn save_config(config: &PomoConfig) -> Result<()> {
+    let path = config_path()?;
+    save_config_to(config, &path)
+}
+
+fn save_config_to(config: &PomoConfig, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create config directory {}", parent.display()))?;
+    }
+
+    let file = ConfigFile {
+        work_time: Some(config.work_time.as_secs()),
+        short_break: Some(config.short_break.as_secs()),
+        long_break: Some(config.long_break.as_secs()),
+        sessions_before_long_break: Some(config.sessions_before_long_break),
+        sound_file: config.sound_file.clone(),
+        sound_enabled: Some(config.sound_enabled),
+        compact_time: Some(config.compact_time),
+        big_text: Some(config.big_text),
+    };
+
+    let contents = toml::to_string_pretty(&file).context("failed to serialize config")?;
+    fs::write(path, contents)
+        .with_context(|| format!("failed to write config file at {}", path.display()))
+}
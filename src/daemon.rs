@@ -0,0 +1,158 @@
+use crate::input::Command;
+use crate::porsmo::pomodoro::PomoState;
+use crate::prelude::*;
+use crate::Alertable;
+use crate::CounterUIState;
+use porsmo::pomodoro::PomoConfig;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How often the daemon wakes up to check whether the current phase has
+/// ended and an alert is due, between servicing client connections.
+const TICK: Duration = Duration::from_millis(200);
+
+/// Control messages a client sends over the socket. These map directly
+/// onto the subset of `Command` that makes sense without a focused TUI.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ControlMessage {
+    Pause,
+    Resume,
+    Skip,
+    Toggle,
+    /// Confirms the end of the current phase once it has run past its
+    /// target (or confirms a pending skip), the same action the TUI's
+    /// `[Enter]`/`[Y]` key performs. Without this, a headless daemon would
+    /// get stuck re-alerting forever once the first phase ended, since
+    /// nothing else can ever call `session.next()`.
+    Advance,
+    Status,
+}
+
+impl ControlMessage {
+    /// The `Command` this message maps onto, or `None` for `Status`,
+    /// which only reads state back rather than mutating it.
+    fn as_command(self) -> Option<Command> {
+        match self {
+            ControlMessage::Pause => Some(Command::Pause),
+            ControlMessage::Resume => Some(Command::Resume),
+            ControlMessage::Skip => Some(Command::Skip),
+            ControlMessage::Toggle => Some(Command::Toggle),
+            ControlMessage::Advance => Some(Command::Enter),
+            ControlMessage::Status => None,
+        }
+    }
+}
+
+/// Snapshot of the daemon's state, sent back in reply to every control
+/// message so a client always learns the outcome of its request.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    pub mode: String,
+    pub session_number: u32,
+    pub time_remaining_secs: u64,
+}
+
+impl From<&PomoState> for DaemonStatus {
+    fn from(state: &PomoState) -> Self {
+        Self {
+            mode: state.mode_label().to_owned(),
+            session_number: state.session_number(),
+            time_remaining_secs: state.time_remaining().as_secs(),
+        }
+    }
+}
+
+/// Default socket path, placed alongside other transient runtime files
+/// rather than the config directory since it has no meaning across reboots.
+pub fn default_socket_path() -> PathBuf {
+    std::env::temp_dir().join("porsmo.sock")
+}
+
+/// Removes the socket file on drop so a clean daemon exit never leaves a
+/// stale path behind for the next `bind` to trip over.
+struct SocketGuard(PathBuf);
+
+impl Drop for SocketGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Runs the pomodoro state machine headlessly, accepting control
+/// connections on `socket_path` instead of reading keypresses from a TUI.
+pub fn run_daemon(config: PomoConfig, socket_path: PathBuf) -> Result<()> {
+    if socket_path.exists() {
+        std::fs::remove_file(&socket_path).with_context(|| {
+            format!("failed to remove stale socket at {}", socket_path.display())
+        })?;
+    }
+
+    let listener = UnixListener::bind(&socket_path)
+        .with_context(|| format!("failed to bind daemon socket at {}", socket_path.display()))?;
+    listener
+        .set_nonblocking(true)
+        .context("failed to set daemon socket to non-blocking")?;
+    let _guard = SocketGuard(socket_path);
+
+    let mut state = PomoState::from(config);
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                state = handle_connection(stream, state)
+                    .context("failed to service daemon client")?;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(err) => return Err(err).context("daemon socket accept failed"),
+        }
+
+        if state.should_alert() && !state.alerted() {
+            state.alert();
+            state.set_alert(true);
+        }
+
+        std::thread::sleep(TICK);
+    }
+}
+
+fn handle_connection(mut stream: UnixStream, state: PomoState) -> Result<PomoState> {
+    let message: ControlMessage = read_message(&mut stream)?;
+
+    let next_state = match message.as_command() {
+        Some(command) => state.handle_command(command).unwrap_or_default(),
+        None => state,
+    };
+
+    write_message(&mut stream, &DaemonStatus::from(&next_state))?;
+    Ok(next_state)
+}
+
+/// Connects to a running daemon, sends a single control message, and
+/// returns the status it replies with.
+pub fn send_command(socket_path: &std::path::Path, message: ControlMessage) -> Result<DaemonStatus> {
+    let mut stream = UnixStream::connect(socket_path)
+        .with_context(|| format!("failed to connect to daemon at {}", socket_path.display()))?;
+    write_message(&mut stream, &message)?;
+    read_message(&mut stream)
+}
+
+fn write_message(stream: &mut UnixStream, message: &impl Serialize) -> Result<()> {
+    let payload = bincode::serialize(message).context("failed to encode daemon message")?;
+    let len = u32::try_from(payload.len()).context("daemon message too large")?;
+    stream.write_all(&len.to_le_bytes())?;
+    stream.write_all(&payload)?;
+    Ok(())
+}
+
+fn read_message<T: for<'de> Deserialize<'de>>(stream: &mut UnixStream) -> Result<T> {
+    let mut len_buf = [0u8; 4];
+    stream.read_exact(&mut len_buf)?;
+    let len = u32::from_le_bytes(len_buf) as usize;
+
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+    bincode::deserialize(&payload).context("failed to decode daemon message")
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use std::time::Duration;
+
+/// Parses a human-friendly duration string such as `"25m"`, `"1h30m"`, or
+/// `"90s"` into a `Duration`, for use as a clap value parser on the
+/// work/break interval flags.
+///
+/// Delegates to the `humantime` crate's own grammar so the accepted
+/// syntax matches what users may already know from other CLI tools.
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    humantime::parse_duration(input)
+        .with_context(|| format!("'{input}' is not a valid duration (try \"25m\" or \"1h30m\")"))
+}
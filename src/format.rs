@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Renders `duration` as a fixed-width clock string, e.g. `24:59` or, once
+/// the hour mark is crossed, `01:24:59`.
+pub fn format_duration(duration: &Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+
+    if hours > 0 {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Renders `duration` as a compact human string, e.g. `24m59s` or
+/// `1h30m0s`, dropping any unit that would be zero at the front (so a
+/// sub-minute duration prints as `59s`, not `0h0m59s`).
+pub fn format_duration_compact(duration: &Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+
+    if hours > 0 {
+        format!("{hours}h{minutes}m{seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m{seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
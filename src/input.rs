@@ -0,0 +1,51 @@
+use crate::prelude::*;
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
+use std::time::Duration;
+
+/// How long `get_event` waits for a keypress before giving up, so the
+/// caller can come back around its loop and redraw or recheck timers even
+/// when the user isn't pressing anything.
+pub const TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Normalized keyboard commands the UI state machines react to.
+#[derive(Debug, Clone, Copy)]
+pub enum Command {
+    Quit,
+    No,
+    Yes,
+    Enter,
+    Pause,
+    Resume,
+    Toggle,
+    Skip,
+    Other,
+}
+
+impl From<KeyEvent> for Command {
+    fn from(event: KeyEvent) -> Self {
+        match event.code {
+            KeyCode::Char('q') => Command::Quit,
+            KeyCode::Char('n') | KeyCode::Char('N') => Command::No,
+            KeyCode::Char('y') | KeyCode::Char('Y') => Command::Yes,
+            KeyCode::Enter => Command::Enter,
+            KeyCode::Char(' ') => Command::Toggle,
+            KeyCode::Char('p') => Command::Pause,
+            KeyCode::Char('r') => Command::Resume,
+            KeyCode::Char('S') => Command::Skip,
+            _ => Command::Other,
+        }
+    }
+}
+
+/// Polls for a keyboard event for up to `timeout`, ignoring anything that
+/// isn't a key press (resizes, mouse events, ...).
+pub fn get_event(timeout: Duration) -> Result<Option<KeyEvent>> {
+    if !event::poll(timeout).context("failed to poll for terminal input")? {
+        return Ok(None);
+    }
+
+    match event::read().context("failed to read terminal input")? {
+        Event::Key(key) => Ok(Some(key)),
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,244 @@
+use clap::{Parser, Subcommand};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+mod alert;
+mod audio;
+mod bigtext;
+mod config;
+mod daemon;
+mod duration_arg;
+mod format;
+mod input;
+mod porsmo;
+mod prelude;
+mod stats;
+mod terminal;
+
+use input::Command;
+use porsmo::pomodoro::PomoState;
+use prelude::*;
+
+/// Shared behavior for the timer UIs driven by the keypress-and-redraw
+/// loop in `run`: fold a `Command` into the next state, or render the
+/// current one.
+pub trait CounterUIState: Sized {
+    fn handle_command(self, command: Command) -> Option<Self>;
+    fn show(&self, out: &mut impl Write) -> Result<()>;
+}
+
+/// Lets `run` fire a phase-transition alert (desktop notification, plus
+/// whatever else the state wants) exactly once per transition.
+pub trait Alertable {
+    fn alert(&mut self);
+    fn alerted(&self) -> bool;
+    fn set_alert(&mut self, alert: bool);
+    fn should_alert(&self) -> bool;
+}
+
+/// A pomodoro timer for your terminal.
+#[derive(Parser)]
+#[command(name = "porsmo", about = "A pomodoro timer for your terminal")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<TopCommand>,
+
+    #[command(flatten)]
+    overrides: IntervalArgs,
+
+    /// Save the effective configuration (after these flags are applied)
+    /// back to settings.toml, so future runs don't need them repeated.
+    #[arg(long)]
+    save: bool,
+}
+
+#[derive(Subcommand)]
+enum TopCommand {
+    /// Run the timer headlessly, controlled over a Unix socket instead
+    /// of a TUI.
+    Daemon {
+        /// Unix socket path to listen on. Defaults to a path under the
+        /// system temp directory.
+        #[arg(long)]
+        socket: Option<PathBuf>,
+    },
+    /// Send a control message to a running daemon.
+    Client {
+        #[command(subcommand)]
+        action: ClientAction,
+
+        /// Unix socket path to connect to. Must match the daemon's.
+        #[arg(long)]
+        socket: Option<PathBuf>,
+    },
+    /// Print cumulative focus-time statistics.
+    Stats,
+}
+
+/// The daemon actions a client can request, mirroring `daemon::ControlMessage`.
+#[derive(Subcommand)]
+enum ClientAction {
+    Pause,
+    Resume,
+    Skip,
+    Toggle,
+    /// Confirm the end of a finished (or skipped) phase.
+    Advance,
+    Status,
+}
+
+impl From<ClientAction> for daemon::ControlMessage {
+    fn from(action: ClientAction) -> Self {
+        match action {
+            ClientAction::Pause => daemon::ControlMessage::Pause,
+            ClientAction::Resume => daemon::ControlMessage::Resume,
+            ClientAction::Skip => daemon::ControlMessage::Skip,
+            ClientAction::Toggle => daemon::ControlMessage::Toggle,
+            ClientAction::Advance => daemon::ControlMessage::Advance,
+            ClientAction::Status => daemon::ControlMessage::Status,
+        }
+    }
+}
+
+/// CLI flags that override `settings.toml`, mirroring `config::ConfigOverrides`.
+#[derive(clap::Args)]
+struct IntervalArgs {
+    /// Work phase length, accepting human-friendly durations like "25m"
+    /// or "1h30m".
+    #[arg(long, value_parser = duration_arg::parse_duration)]
+    work: Option<Duration>,
+
+    /// Short break length, accepting human-friendly durations like "5m".
+    #[arg(long = "short-break", value_parser = duration_arg::parse_duration)]
+    short_break: Option<Duration>,
+
+    /// Long break length, accepting human-friendly durations like "25m".
+    #[arg(long = "long-break", value_parser = duration_arg::parse_duration)]
+    long_break: Option<Duration>,
+
+    /// Number of work sessions before a long break is taken.
+    #[arg(long = "sessions-before-long-break")]
+    sessions_before_long_break: Option<u32>,
+
+    /// Path to a sound file to play on phase transitions.
+    #[arg(long = "sound-file")]
+    sound_file: Option<PathBuf>,
+
+    /// Disable the audio alert on phase transitions.
+    #[arg(long = "no-sound")]
+    no_sound: bool,
+
+    /// Render the countdown compactly (e.g. `24m59s`) instead of `MM:SS`.
+    #[arg(long = "compact-time")]
+    compact_time: bool,
+
+    /// Render the countdown as large block digits.
+    #[arg(long = "big-text")]
+    big_text: bool,
+}
+
+impl From<IntervalArgs> for config::ConfigOverrides {
+    fn from(args: IntervalArgs) -> Self {
+        Self {
+            work_time: args.work,
+            short_break: args.short_break,
+            long_break: args.long_break,
+            sessions_before_long_break: args.sessions_before_long_break,
+            sound_file: args.sound_file,
+            no_sound: args.no_sound,
+            compact_time: args.compact_time.then_some(true),
+            big_text: args.big_text.then_some(true),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(TopCommand::Daemon { socket }) => {
+            let socket_path = socket.unwrap_or_else(daemon::default_socket_path);
+            let config = config::load_config(cli.overrides.into())?;
+            daemon::run_daemon(config, socket_path)
+        }
+        Some(TopCommand::Client { action, socket }) => {
+            let socket_path = socket.unwrap_or_else(daemon::default_socket_path);
+            let status = daemon::send_command(&socket_path, action.into())?;
+            println!(
+                "{} - session {} - {}s remaining",
+                status.mode, status.session_number, status.time_remaining_secs
+            );
+            Ok(())
+        }
+        Some(TopCommand::Stats) => print_stats(),
+        None => run_interactive(cli.overrides, cli.save),
+    }
+}
+
+fn print_stats() -> Result<()> {
+    let sessions = stats::read_sessions()?;
+    let config = config::load_config(config::ConfigOverrides::default())?;
+    let summary = stats::summarize(
+        &sessions,
+        chrono::Local::now(),
+        config.sessions_before_long_break,
+    );
+
+    println!(
+        "Focus today: {}",
+        format::format_duration_compact(&summary.focus_today)
+    );
+    println!(
+        "Focus this week: {}",
+        format::format_duration_compact(&summary.focus_this_week)
+    );
+    println!(
+        "Completed {}-session cycles: {}",
+        summary.sessions_before_long_break, summary.completed_cycles
+    );
+
+    Ok(())
+}
+
+fn run_interactive(overrides: IntervalArgs, save: bool) -> Result<()> {
+    let config = config::load_config(overrides.into())?;
+
+    if save {
+        config::save_config(&config)?;
+    }
+
+    let state = PomoState::from(config);
+
+    enable_raw_mode().context("failed to enable raw terminal mode")?;
+    let result = run(&mut std::io::stdout(), state);
+    disable_raw_mode().context("failed to disable raw terminal mode")?;
+    result
+}
+
+/// Drives a `CounterUIState` to completion: redraw, fire an alert once a
+/// phase is overdue, poll for a keypress, and fold it into the next
+/// state, until a command signals the state machine should exit.
+fn run<S>(out: &mut impl Write, mut state: S) -> Result<()>
+where
+    S: CounterUIState + Alertable,
+{
+    loop {
+        state.show(out)?;
+
+        if state.should_alert() && !state.alerted() {
+            state.alert();
+            state.set_alert(true);
+        }
+
+        if let Some(key) = input::get_event(input::TIMEOUT)? {
+            match state.handle_command(Command::from(key)) {
+                Some(next) => state = next,
+                None => break,
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use crate::alert::alert;
-use crate::input::{get_event, TIMEOUT};
-use crate::stopwatch::Stopwatch;
 use crate::terminal::running_color;
-use crate::{format::format_duration, input::Command};
+use crate::{
+    format::{format_duration, format_duration_compact},
+    input::Command,
+};
 use crate::{prelude::*, Alertable, CounterUIState};
 use crossterm::cursor::{MoveTo, MoveToNextLine};
 use crossterm::style::Print;
@@ -15,8 +16,9 @@ use crossterm::{
 use porsmo::counter::Counter;
 use porsmo::pomodoro::{PomoConfig, PomodoroMode as Mode, PomodoroSession as Session};
 
+use std::borrow::Cow;
 use std::io::Write;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 #[derive(Debug, Default)]
 pub struct PomoState {
@@ -69,11 +71,53 @@ fn pomodoro_break_title(next_mode: Mode) -> &'static str {
     }
 }
 
-pub fn pomodoro_alert_message(next_mode: Mode) -> (&'static str, &'static str) {
+/// Computes the session that follows `session`, honoring
+/// `config.sessions_before_long_break` instead of a hardcoded 4-session
+/// cadence. A Work phase advances the session count and rolls over to a
+/// `LongBreak` once that count reaches the configured cadence, otherwise
+/// to a plain `Break`; either break phase always returns to `Work`.
+fn advance_session(session: Session, config: &PomoConfig) -> Session {
+    match session.mode {
+        Mode::Work => {
+            let number = session.number + 1;
+            let cadence = config.sessions_before_long_break.max(1);
+            let mode = if number % cadence == 0 {
+                Mode::LongBreak
+            } else {
+                Mode::Break
+            };
+            Session { number, mode }
+        }
+        Mode::Break | Mode::LongBreak => Session {
+            number: session.number,
+            mode: Mode::Work,
+        },
+    }
+}
+
+/// Builds the (title, message) pair shown when `next_mode` is reached.
+/// The `LongBreak` title names how many sessions were just completed, so
+/// it needs `sessions_before_long_break` rather than assuming a fixed
+/// cadence of 4.
+pub fn pomodoro_alert_message(
+    next_mode: Mode,
+    sessions_before_long_break: u32,
+) -> (Cow<'static, str>, Cow<'static, str>) {
     match next_mode {
-        Mode::Work => ("Your break ended!", "Time for some work"),
-        Mode::Break => ("Pomodoro ended!", "Time for a short break"),
-        Mode::LongBreak => ("Pomodoro 4 sessions complete!", "Time for a long break"),
+        Mode::Work => (
+            Cow::Borrowed("Your break ended!"),
+            Cow::Borrowed("Time for some work"),
+        ),
+        Mode::Break => (
+            Cow::Borrowed("Pomodoro ended!"),
+            Cow::Borrowed("Time for a short break"),
+        ),
+        Mode::LongBreak => (
+            Cow::Owned(format!(
+                "Pomodoro {sessions_before_long_break} sessions complete!"
+            )),
+            Cow::Borrowed("Time for a long break"),
+        ),
     }
 }
 
@@ -102,9 +146,10 @@ impl CounterUIState for PomoState {
                 PomoStateMode::Running { counter }
                     if counter.elapsed() >= self.session.mode.current_target(&self.config) =>
                 {
+                    self.record_completed_session();
                     let counter = Counter::default().start();
                     let mode = PomoStateMode::Running { counter };
-                    let session = self.session.next();
+                    let session = advance_session(self.session, &self.config);
                     Some(Self {
                         mode,
                         session,
@@ -113,9 +158,10 @@ impl CounterUIState for PomoState {
                     })
                 }
                 PomoStateMode::Skip { .. } => {
+                    self.record_completed_session();
                     let counter = Counter::default().start();
                     let mode = PomoStateMode::Running { counter };
-                    let session = self.session.next();
+                    let session = advance_session(self.session, &self.config);
                     Some(Self {
                         mode,
                         session,
@@ -128,9 +174,10 @@ impl CounterUIState for PomoState {
 
             Command::Yes => match self.mode {
                 PomoStateMode::Skip { .. } => {
+                    self.record_completed_session();
                     let counter = Counter::default().start();
                     let mode = PomoStateMode::Running { counter };
-                    let session = self.session.next();
+                    let session = advance_session(self.session, &self.config);
                     Some(Self {
                         mode,
                         session,
@@ -186,7 +233,7 @@ impl CounterUIState for PomoState {
         let round_number = format!("Session: {}", self.session.number);
         match self.mode {
             PomoStateMode::Skip { .. } => {
-                let (color, skip_to) = match self.session.next().mode {
+                let (color, skip_to) = match advance_session(self.session, &self.config).mode {
                     Mode::Work => (Color::Red, "skip to work?"),
                     Mode::Break => (Color::Green, "skip to break?"),
                     Mode::LongBreak => (Color::Green, "skip to long break?"),
@@ -203,38 +250,81 @@ impl CounterUIState for PomoState {
             PomoStateMode::Running { counter } if counter.elapsed() < target => {
                 let time_left = target.saturating_sub(counter.elapsed());
 
-                queue!(
-                    out,
-                    MoveTo(0, 0),
-                    Clear(ClearType::All),
-                    Print(pomodoro_work_title(self.session.mode)), MoveToNextLine(1),
-                    Print(
-                        format_duration(&time_left)
-                            .with(running_color(counter.started())),
-                    ), MoveToNextLine(1),
-                    Print(CONTROLS), MoveToNextLine(1),
-                    Print(round_number),
-                )?;
+                if self.config.big_text {
+                    queue!(
+                        out,
+                        MoveTo(0, 0),
+                        Clear(ClearType::All),
+                        Print(pomodoro_work_title(self.session.mode)),
+                    )?;
+                    // The block-glyph table only covers digits, `:` and
+                    // `+`, so big text always renders the clock format
+                    // (`MM:SS`) here regardless of `compact_time` — the
+                    // compact format's unit letters ("m"/"s") have no
+                    // glyph and would render as blank gaps.
+                    crate::bigtext::show_big_text(out, &format_duration(&time_left), 2)?;
+                    queue!(
+                        out,
+                        MoveTo(0, 8),
+                        Print(CONTROLS), MoveToNextLine(1),
+                        Print(round_number),
+                    )?;
+                } else {
+                    queue!(
+                        out,
+                        MoveTo(0, 0),
+                        Clear(ClearType::All),
+                        Print(pomodoro_work_title(self.session.mode)), MoveToNextLine(1),
+                        Print(
+                            self.format_time(&time_left)
+                                .with(running_color(counter.started())),
+                        ), MoveToNextLine(1),
+                        Print(CONTROLS), MoveToNextLine(1),
+                        Print(round_number),
+                    )?;
+                }
             }
             PomoStateMode::Running { counter } => {
                 let excess_time = counter.elapsed().saturating_sub(target);
-                let (_, message) = pomodoro_alert_message(self.session.next().mode);
-
-                queue!(
-                    out,
-                    MoveTo(0, 0),
-                    Clear(ClearType::All),
-                    Print(pomodoro_break_title(self.session.next().mode)), MoveToNextLine(1),
-                    Print(
-                        format_args!(
-                            "+{}",
-                            format_duration(&excess_time)
-                                .with(running_color(counter.started())),
-                        ),
-                    ), MoveToNextLine(1),
-                    Print(ENDING_CONTROLS), MoveToNextLine(1),
-                    Print(message),
-                )?;
+                let (_, message) = pomodoro_alert_message(
+                    advance_session(self.session, &self.config).mode,
+                    self.config.sessions_before_long_break,
+                );
+
+                if self.config.big_text {
+                    queue!(
+                        out,
+                        MoveTo(0, 0),
+                        Clear(ClearType::All),
+                        Print(pomodoro_break_title(advance_session(self.session, &self.config).mode)),
+                    )?;
+                    // Same reasoning as the countdown above: force the
+                    // clock format so every character has a block glyph.
+                    let excess_text = format!("+{}", format_duration(&excess_time));
+                    crate::bigtext::show_big_text(out, &excess_text, 2)?;
+                    queue!(
+                        out,
+                        MoveTo(0, 8),
+                        Print(ENDING_CONTROLS), MoveToNextLine(1),
+                        Print(message),
+                    )?;
+                } else {
+                    queue!(
+                        out,
+                        MoveTo(0, 0),
+                        Clear(ClearType::All),
+                        Print(pomodoro_break_title(advance_session(self.session, &self.config).mode)), MoveToNextLine(1),
+                        Print(
+                            format_args!(
+                                "+{}",
+                                self.format_time(&excess_time)
+                                    .with(running_color(counter.started())),
+                            ),
+                        ), MoveToNextLine(1),
+                        Print(ENDING_CONTROLS), MoveToNextLine(1),
+                        Print(message),
+                    )?;
+                }
             }
         }
         out.flush()?;
@@ -253,12 +343,57 @@ impl PomoState {
     fn target(&self) -> Duration {
         self.session.mode.current_target(&self.config)
     }
+
+    /// Time left in the current phase, or overrun if it has already ended.
+    /// Used by the daemon to report status to clients.
+    pub(crate) fn time_remaining(&self) -> Duration {
+        self.target().saturating_sub(self.elpased())
+    }
+
+    pub(crate) fn session_number(&self) -> u32 {
+        self.session.number
+    }
+
+    /// Appends the just-finished Work phase to the stats log. A no-op for
+    /// Break/LongBreak phases, which aren't tracked as focus time.
+    fn record_completed_session(&self) {
+        if self.session.mode == Mode::Work {
+            crate::stats::record_completed_work_session(self.target(), self.elpased());
+        }
+    }
+
+    pub(crate) fn mode_label(&self) -> &'static str {
+        match self.mode {
+            PomoStateMode::Skip { .. } => "skip",
+            PomoStateMode::Running { .. } => match self.session.mode {
+                Mode::Work => "work",
+                Mode::Break => "break",
+                Mode::LongBreak => "long_break",
+            },
+        }
+    }
+
+    /// Renders a duration using whichever style the config asks for: the
+    /// fixed `MM:SS` clock, or a compact human string like `24m59s`.
+    fn format_time(&self, duration: &Duration) -> String {
+        if self.config.compact_time {
+            format_duration_compact(duration)
+        } else {
+            format_duration(duration)
+        }
+    }
 }
 
 impl Alertable for PomoState {
     fn alert(&mut self) {
-        let (title, message) = pomodoro_alert_message(self.session.next().mode);
-        alert(title, message);
+        let (title, message) = pomodoro_alert_message(
+            advance_session(self.session, &self.config).mode,
+            self.config.sessions_before_long_break,
+        );
+        alert(title.as_ref(), message.as_ref());
+        if self.config.sound_enabled {
+            crate::audio::play_alert_sound(self.config.sound_file.as_deref());
+        }
     }
 
     fn alerted(&self) -> bool {
@@ -273,121 +408,3 @@ impl Alertable for PomoState {
         self.elpased() > self.target()
     }
 }
-
-enum UIMode {
-    Skip(Duration),
-    Running(Stopwatch),
-}
-
-pub fn pomodoro(out: &mut impl Write, config: &PomoConfig) -> Result<()> {
-    let stopwatch = Stopwatch::default();
-    let mut session = Session::default();
-    let mut ui_mode = UIMode::Running(stopwatch);
-
-    loop {
-        pomodoro_show(out, config, &ui_mode, &session)?;
-
-        if let Some(cmd) = get_event(TIMEOUT)?.map(Command::from) {
-            match ui_mode {
-                UIMode::Skip(elapsed) => {
-                    match cmd {
-                        Command::Quit | Command::No => ui_mode =
-                            UIMode::Running(Stopwatch::new(
-                                Some(Instant::now()), elapsed
-                            )),
-                        Command::Enter | Command::Yes => {
-                            ui_mode = UIMode::Running(Stopwatch::default());
-                            session = session.next();
-                        },
-                        _ => (),
-                    }
-                },
-                UIMode::Running(ref mut stopwatch) => {
-                    let elapsed = stopwatch.elapsed();
-                    let target_time = session.mode.current_target(config);
-
-                    match cmd {
-                        Command::Quit => break,
-
-                        Command::Enter if elapsed >= target_time => {
-                            ui_mode = UIMode::Running(Stopwatch::default());
-                            session = session.next();
-                        },
-                        Command::Pause => stopwatch.stop(),
-                        Command::Resume => stopwatch.start(),
-                        Command::Toggle => stopwatch.toggle(),
-                        Command::Skip => ui_mode = UIMode::Skip(elapsed),
-
-                        _ => (),
-                    }
-                },
-            }
-        }
-    }
-    Ok(())
-}
-
-fn pomodoro_show(
-    out: &mut impl Write,
-    config: &PomoConfig,
-    ui_mode: &UIMode,
-    session: &Session,
-) -> Result<()> {
-    let target = session.mode.current_target(config);
-    let round_number = format!("Session: {}", session.number);
-    match ui_mode {
-        UIMode::Skip(..) => {
-            let (color, skip_to) = match session.next().mode {
-                Mode::Work => (Color::Red, "skip to work?"),
-                Mode::Break => (Color::Green, "skip to break?"),
-                Mode::LongBreak => (Color::Green, "skip to long break?"),
-            };
-            queue!(
-                out,
-                MoveTo(0, 0),
-                Clear(ClearType::All),
-                Print(skip_to.with(color)), MoveToNextLine(1),
-                Print(round_number), MoveToNextLine(1),
-                Print(SKIP_CONTROLS)
-            )?;
-        }
-        UIMode::Running(stopwatch)  if stopwatch.elapsed() < target => {
-            let time_left = target.saturating_sub(stopwatch.elapsed());
-
-            queue!(
-                out,
-                MoveTo(0, 0),
-                Clear(ClearType::All),
-                Print(pomodoro_work_title(session.mode)), MoveToNextLine(1),
-                Print(
-                    format_duration(&time_left)
-                        .with(running_color(stopwatch.started())),
-                ), MoveToNextLine(1),
-                Print(CONTROLS), MoveToNextLine(1),
-                Print(round_number),
-            )?;
-        }
-        UIMode::Running(stopwatch) => {
-            let excess_time = stopwatch.elapsed().saturating_sub(target);
-            let (_, message) = pomodoro_alert_message(session.next().mode);
-
-            queue!(
-                out,
-                MoveTo(0, 0),
-                Clear(ClearType::All),
-                Print(pomodoro_break_title(session.next().mode)), MoveToNextLine(1),
-                Print(
-                    format_args!(
-                        "+{}",
-                        format_duration(&excess_time)
-                            .with(running_color(stopwatch.started())),
-                    ),
-                ), MoveToNextLine(1),
-                Print(ENDING_CONTROLS), MoveToNextLine(1),
-                Print(message),
-            )?;
-        }
-    }
-    out.flush()?;
-    Ok(())
-}
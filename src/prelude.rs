@@ -0,0 +1 @@
+pub use anyhow::{Context, Result};
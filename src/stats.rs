@@ -0,0 +1,267 @@
+use crate::config::config_dir;
+use crate::prelude::*;
+use chrono::{DateTime, Duration as ChronoDuration, LocalResult, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One completed Work phase, appended to the stats log as a single JSON
+/// line so the file can grow forever without ever needing a rewrite.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompletedSession {
+    /// Unix timestamp of when the session was marked complete.
+    pub completed_at_secs: u64,
+    /// How long the session actually ran, including any overrun.
+    pub elapsed_secs: u64,
+    /// How far past the configured target it ran, if at all.
+    pub overrun_secs: u64,
+}
+
+fn stats_path() -> Result<std::path::PathBuf> {
+    Ok(config_dir()?.join("stats.log"))
+}
+
+/// Appends a completed Work phase to the stats log.
+///
+/// Failures are logged and swallowed rather than propagated: a session
+/// should never fail to advance just because its history couldn't be
+/// written to disk.
+pub fn record_completed_work_session(target: Duration, elapsed: Duration) {
+    if let Err(err) = try_record(target, elapsed) {
+        eprintln!("porsmo: failed to record session stats: {err}");
+    }
+}
+
+fn try_record(target: Duration, elapsed: Duration) -> Result<()> {
+    let path = stats_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create stats directory {}", parent.display()))?;
+    }
+
+    let entry = CompletedSession {
+        completed_at_secs: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        elapsed_secs: elapsed.as_secs(),
+        overrun_secs: elapsed.saturating_sub(target).as_secs(),
+    };
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open stats log at {}", path.display()))?;
+
+    let line = serde_json::to_string(&entry).context("failed to serialize stats entry")?;
+    writeln!(file, "{line}").context("failed to append stats entry")
+}
+
+/// Reads every completed session recorded so far, oldest first.
+pub fn read_sessions() -> Result<Vec<CompletedSession>> {
+    let path = stats_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file =
+        std::fs::File::open(&path).with_context(|| format!("failed to open {}", path.display()))?;
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map(|l| !l.trim().is_empty()).unwrap_or(true))
+        .map(|line| {
+            let line = line.context("failed to read stats log line")?;
+            serde_json::from_str(&line).context("failed to parse stats log line")
+        })
+        .collect()
+}
+
+/// Totals shown by the `--stats` subcommand.
+#[derive(Debug, Default)]
+pub struct StatsSummary {
+    pub focus_today: Duration,
+    pub focus_this_week: Duration,
+    pub completed_cycles: u32,
+    pub sessions_before_long_break: u32,
+}
+
+/// Aggregates the raw session log into the numbers a user actually wants
+/// to see: focus time today/this week, and how many full
+/// `sessions_before_long_break`-session cycles they've completed.
+///
+/// "Today" and "this week" are measured in `now`'s own calendar, not UTC,
+/// so sessions aren't misattributed to the wrong day around local
+/// midnight. Generic over the time zone so a DST-observing zone can be
+/// injected in tests; production code always calls this with `Local`.
+pub fn summarize<Tz: TimeZone>(
+    sessions: &[CompletedSession],
+    now: DateTime<Tz>,
+    sessions_before_long_break: u32,
+) -> StatsSummary {
+    let tz = now.timezone();
+    let today_start = local_midnight(&now, &tz);
+    let week_start = now.clone() - ChronoDuration::days(7);
+
+    let mut summary = StatsSummary {
+        sessions_before_long_break,
+        ..Default::default()
+    };
+
+    for session in sessions {
+        let Some(completed_at) = epoch_secs_to(session.completed_at_secs, &tz) else {
+            continue;
+        };
+        let duration = Duration::from_secs(session.elapsed_secs);
+
+        if completed_at >= today_start {
+            summary.focus_today += duration;
+        }
+        if completed_at >= week_start {
+            summary.focus_this_week += duration;
+        }
+    }
+
+    if sessions_before_long_break > 0 {
+        summary.completed_cycles = sessions.len() as u32 / sessions_before_long_break;
+    }
+
+    summary
+}
+
+/// The instant `now`'s calendar day began, in `tz`.
+///
+/// `NaiveDateTime::and_hms_opt(0, 0, 0)` always succeeds, but resolving
+/// that wall-clock midnight against a real time zone can still hit every
+/// variant of `LocalResult`: it's `Single` almost everywhere, `Ambiguous`
+/// when a "fall back" transition repeats the hour containing midnight,
+/// and `None` when a "spring forward" transition (or, historically, a
+/// whole-day skip like Samoa's 2011 date-line move) removes it entirely.
+/// Ambiguous resolves to the earlier of the two instants, since that's
+/// the actual start of the calendar day; None walks forward a minute at
+/// a time to the first wall-clock time that does exist, so a whole day's
+/// sessions aren't silently dropped from "today".
+fn local_midnight<Tz: TimeZone>(now: &DateTime<Tz>, tz: &Tz) -> DateTime<Tz> {
+    let midnight = now
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .expect("00:00:00 is always a valid naive time");
+
+    match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _latest) => earliest,
+        LocalResult::None => (1..=24 * 60)
+            .find_map(|minutes| {
+                let candidate = midnight + ChronoDuration::minutes(minutes);
+                tz.from_local_datetime(&candidate).single()
+            })
+            .unwrap_or_else(|| now.clone()),
+    }
+}
+
+fn epoch_secs_to<Tz: TimeZone>(secs: u64, tz: &Tz) -> Option<DateTime<Tz>> {
+    tz.timestamp_opt(i64::try_from(secs).ok()?, 0).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate, NaiveDateTime, Offset};
+
+    /// A toy time zone whose only rule: wall-clock time between
+    /// `self.0` (inclusive) and one hour later (exclusive) never
+    /// happened, as if a "spring forward" transition occurred there.
+    /// Lets `local_midnight`'s `LocalResult::None` branch be exercised
+    /// deterministically, without depending on real-world tzdata.
+    #[derive(Clone)]
+    struct GapAt(NaiveDateTime);
+
+    #[derive(Clone, Debug)]
+    struct Utc0;
+
+    impl Offset for Utc0 {
+        fn fix(&self) -> FixedOffset {
+            FixedOffset::east_opt(0).unwrap()
+        }
+    }
+
+    impl TimeZone for GapAt {
+        type Offset = Utc0;
+
+        fn from_offset(_offset: &Utc0) -> Self {
+            GapAt(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<Utc0> {
+            self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<Utc0> {
+            if *local >= self.0 && *local < self.0 + ChronoDuration::hours(1) {
+                LocalResult::None
+            } else {
+                LocalResult::Single(Utc0)
+            }
+        }
+
+        fn offset_from_utc_date(&self, _utc: &NaiveDate) -> Utc0 {
+            Utc0
+        }
+
+        fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> Utc0 {
+            Utc0
+        }
+    }
+
+    #[test]
+    fn local_midnight_walks_forward_out_of_a_dst_gap() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let gap_start = today.and_hms_opt(0, 0, 0).unwrap();
+        let tz = GapAt(gap_start);
+
+        // `now` is later the same day, past the gap, so it resolves fine.
+        let now = tz
+            .from_local_datetime(&today.and_hms_opt(3, 0, 0).unwrap())
+            .single()
+            .unwrap();
+
+        let boundary = local_midnight(&now, &tz);
+
+        // Midnight itself doesn't exist in this zone; the boundary should
+        // land on 01:00:00, the first wall-clock instant after the gap,
+        // not silently fall back to `now`.
+        let expected = tz
+            .from_local_datetime(&today.and_hms_opt(1, 0, 0).unwrap())
+            .single()
+            .unwrap();
+        assert_eq!(boundary, expected);
+    }
+
+    #[test]
+    fn summarize_counts_a_session_completed_right_after_a_dst_gap_as_today() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let gap_start = today.and_hms_opt(0, 0, 0).unwrap();
+        let tz = GapAt(gap_start);
+
+        let now = tz
+            .from_local_datetime(&today.and_hms_opt(3, 0, 0).unwrap())
+            .single()
+            .unwrap();
+        let completed_at = tz
+            .from_local_datetime(&today.and_hms_opt(1, 30, 0).unwrap())
+            .single()
+            .unwrap();
+
+        let sessions = [CompletedSession {
+            completed_at_secs: completed_at.timestamp() as u64,
+            elapsed_secs: 1500,
+            overrun_secs: 0,
+        }];
+
+        let summary = summarize(&sessions, now, 4);
+
+        assert_eq!(summary.focus_today, Duration::from_secs(1500));
+    }
+}
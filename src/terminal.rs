@@ -0,0 +1,12 @@
+use crossterm::style::Color;
+
+/// Color for the countdown text: green while the counter is actively
+/// ticking, yellow while paused, so a glance at the screen shows whether
+/// time is passing without having to read the controls line.
+pub fn running_color(started: bool) -> Color {
+    if started {
+        Color::Green
+    } else {
+        Color::Yellow
+    }
+}